@@ -14,18 +14,41 @@ use tendermint_light_client_verifier::Verdict;
 
 use crate::clients::ics07_tendermint::error::{Error, IntoResult};
 use crate::clients::ics07_tendermint::header::Header;
+use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics02_client::error::ClientError;
+use crate::core::ics02_client::events::{
+    ClientFrozen, ClientIdAttribute, ClientTypeAttribute, ConsensusHeightAttribute,
+    FrozenHeightAttribute, SubmitMisbehaviour,
+};
 use crate::core::ics24_host::identifier::{ChainId, ClientId};
 use crate::Height;
 
+const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
+
 pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Misbehaviour";
 
+/// Classifies what kind of misbehaviour a [`Misbehaviour`] proves, so that
+/// `ics02_client` misbehaviour handling can branch on it when freezing a
+/// client.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MisbehaviourKind {
+    /// `header1` and `header2` are both valid headers at the same height,
+    /// but commit to different block ids: the validator set equivocated.
+    Equivocation,
+    /// `header1` and `header2` are at different heights, but `header2`'s
+    /// block time is not strictly before `header1`'s, violating the
+    /// monotonic time rule a later-height header must satisfy.
+    TimeViolation,
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Misbehaviour {
     client_id: ClientId,
     header1: Header,
     header2: Header,
+    kind: MisbehaviourKind,
 }
 
 impl Misbehaviour {
@@ -46,6 +69,8 @@ impl Misbehaviour {
             });
         }
 
+        let kind = Self::classify(&header1, &header2)?;
+
         let untrusted_state_1 = header1.as_untrusted_block_state();
         let untrusted_state_2 = header2.as_untrusted_block_state();
 
@@ -63,13 +88,93 @@ impl Misbehaviour {
             client_id,
             header1,
             header2,
+            kind,
         })
     }
 
+    /// Checks that `header1` and `header2` genuinely constitute misbehaviour
+    /// and classifies the kind of conflict they prove. Two distinct but
+    /// individually valid headers at unrelated heights prove nothing, and
+    /// are rejected.
+    fn classify(header1: &Header, header2: &Header) -> Result<MisbehaviourKind, Error> {
+        if header1.height() == header2.height() {
+            let hash1 = header1.signed_header.commit.block_id.hash;
+            let hash2 = header2.signed_header.commit.block_id.hash;
+
+            if hash1 == hash2 {
+                return Err(Error::InvalidRawMisbehaviour {
+                    reason: "headers at the same height commit to the same block id, this is not misbehaviour".to_owned(),
+                });
+            }
+
+            return Ok(MisbehaviourKind::Equivocation);
+        }
+
+        // `new` already rejects `header1.height() < header2.height()`, and
+        // the `==` case was just handled above, so `header1` must be the
+        // taller header here. Assert it instead of only relying on that
+        // reasoning, since this branch's classification depends on it.
+        assert!(
+            header1.height() > header2.height(),
+            "classify: header1 must be taller than header2 at this point"
+        );
+
+        let taller_time = header1.signed_header.header.time;
+        let shorter_time = header2.signed_header.header.time;
+
+        // On a legitimately produced chain, a taller header's time is
+        // always strictly after a shorter header's time. A pair that
+        // violates this proves the chain forked or the clock went
+        // backwards; a pair that respects it is just two ordinary
+        // sequential headers and proves nothing.
+        if taller_time <= shorter_time {
+            Ok(MisbehaviourKind::TimeViolation)
+        } else {
+            Err(Error::InvalidRawMisbehaviour {
+                reason: "headers are at unrelated heights and do not violate monotonic time; this pair proves nothing".to_owned(),
+            })
+        }
+    }
+
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
     }
 
+    pub fn kind(&self) -> MisbehaviourKind {
+        self.kind
+    }
+
+    /// The height at which the client should be frozen: the lower of the
+    /// two conflicting headers' heights, since that is the earliest point
+    /// at which the chain's history diverged.
+    pub fn frozen_height(&self) -> Height {
+        self.header2.height()
+    }
+
+    /// Builds the `SubmitMisbehaviour` event emitted when this evidence is
+    /// submitted, before the client is actually frozen.
+    pub fn submit_misbehaviour_event(&self) -> SubmitMisbehaviour {
+        SubmitMisbehaviour {
+            client_id: ClientIdAttribute::from(self.client_id.clone()),
+            client_type: ClientTypeAttribute::from(ClientType::new(
+                TENDERMINT_CLIENT_TYPE.to_string(),
+            )),
+            consensus_height: ConsensusHeightAttribute::from(self.header1.height()),
+        }
+    }
+
+    /// Builds the `ClientFrozen` event emitted once the client has been
+    /// frozen as a result of this misbehaviour.
+    pub fn client_frozen_event(&self) -> ClientFrozen {
+        ClientFrozen {
+            client_id: ClientIdAttribute::from(self.client_id.clone()),
+            client_type: ClientTypeAttribute::from(ClientType::new(
+                TENDERMINT_CLIENT_TYPE.to_string(),
+            )),
+            frozen_height: FrozenHeightAttribute::from(self.frozen_height()),
+        }
+    }
+
     pub fn header1(&self) -> &Header {
         &self.header1
     }
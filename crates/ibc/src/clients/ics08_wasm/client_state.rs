@@ -0,0 +1,283 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawClientState;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::vm::WasmVm;
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics04_channel::events::channel_attributes::ChecksumAttribute;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+/// The length, in bytes, of a SHA-256 digest.
+const CHECKSUM_LENGTH: usize = 32;
+
+/// The client state of an 08-wasm client: an opaque blob holding the inner
+/// light client's own state, plus the checksum of the Wasm bytecode that
+/// this client instance was instantiated against. `verify_client_message`
+/// and misbehaviour handling delegate to the Wasm VM, which loads the
+/// bytecode registered under `checksum` and executes it with `data` and the
+/// serialized header or misbehaviour, returning the updated `data`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub data: Vec<u8>,
+    pub checksum: [u8; CHECKSUM_LENGTH],
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(data: Vec<u8>, checksum: [u8; CHECKSUM_LENGTH], latest_height: Height) -> Self {
+        Self {
+            data,
+            checksum,
+            latest_height,
+        }
+    }
+
+    /// The `checksum` event attribute for this client, to be emitted
+    /// alongside the client-create, client-update and client-upgrade IBC
+    /// events so relayers and indexers can identify the bytecode backing
+    /// this client from the event log alone.
+    pub fn checksum_attribute(&self) -> ChecksumAttribute {
+        ChecksumAttribute {
+            checksum: self.checksum.to_vec(),
+        }
+    }
+
+    fn ensure_code_registered(&self, vm: &dyn WasmVm) -> Result<(), Error> {
+        if vm.has_code(&self.checksum) {
+            Ok(())
+        } else {
+            Err(Error::UnknownCodeChecksum {
+                checksum: hex_checksum(&self.checksum),
+            })
+        }
+    }
+
+    /// Verifies `client_message` (a serialized header or misbehaviour) by
+    /// delegating to the Wasm VM: it loads the bytecode registered under
+    /// `self.checksum` and runs it against `self.data` and `client_message`,
+    /// surfacing whatever verification failure the contract reports.
+    pub fn verify_client_message(
+        &self,
+        vm: &dyn WasmVm,
+        client_message: &[u8],
+    ) -> Result<(), Error> {
+        self.ensure_code_registered(vm)?;
+
+        vm.call(&self.checksum, &self.data, client_message)
+            .map(|_| ())
+            .map_err(|reason| Error::VmError { reason })
+    }
+
+    /// Updates the client from a verified header by delegating to the Wasm
+    /// VM and adopting whatever state bytes it returns, emitted alongside
+    /// the `checksum` attribute of the (unchanged) bytecode that produced
+    /// them.
+    pub fn update_state(
+        &self,
+        vm: &dyn WasmVm,
+        header: &[u8],
+    ) -> Result<(Self, ChecksumAttribute), Error> {
+        self.call_vm_for_update(vm, header)
+    }
+
+    /// Updates the client after misbehaviour has been verified, delegating
+    /// to the Wasm VM the same way `update_state` does; the contract is
+    /// expected to return state bytes that mark the client frozen.
+    pub fn update_state_on_misbehaviour(
+        &self,
+        vm: &dyn WasmVm,
+        misbehaviour: &[u8],
+    ) -> Result<(Self, ChecksumAttribute), Error> {
+        self.call_vm_for_update(vm, misbehaviour)
+    }
+
+    fn call_vm_for_update(
+        &self,
+        vm: &dyn WasmVm,
+        client_message: &[u8],
+    ) -> Result<(Self, ChecksumAttribute), Error> {
+        self.ensure_code_registered(vm)?;
+
+        let data = vm
+            .call(&self.checksum, &self.data, client_message)
+            .map_err(|reason| Error::VmError { reason })?;
+
+        let new_state = Self {
+            data,
+            checksum: self.checksum,
+            latest_height: self.latest_height.clone(),
+        };
+        let checksum_attribute = new_state.checksum_attribute();
+
+        Ok((new_state, checksum_attribute))
+    }
+}
+
+fn hex_checksum(checksum: &[u8; CHECKSUM_LENGTH]) -> String {
+    String::from_utf8(subtle_encoding::hex::encode(checksum))
+        .expect("hex-encoded bytes are valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockVm {
+        has_code: bool,
+        call_result: Result<Vec<u8>, String>,
+    }
+
+    impl WasmVm for MockVm {
+        fn has_code(&self, _checksum: &[u8; CHECKSUM_LENGTH]) -> bool {
+            self.has_code
+        }
+
+        fn call(
+            &self,
+            _checksum: &[u8; CHECKSUM_LENGTH],
+            _data: &[u8],
+            _client_message: &[u8],
+        ) -> Result<Vec<u8>, String> {
+            self.call_result.clone()
+        }
+    }
+
+    fn test_client_state() -> ClientState {
+        ClientState::new(
+            vec![1, 2, 3],
+            [7u8; CHECKSUM_LENGTH],
+            Height::new(0, 1).expect("valid height"),
+        )
+    }
+
+    #[test]
+    fn verify_client_message_fails_for_unregistered_checksum() {
+        let client_state = test_client_state();
+        let vm = MockVm {
+            has_code: false,
+            call_result: Ok(vec![]),
+        };
+
+        let err = client_state
+            .verify_client_message(&vm, &[])
+            .expect_err("unregistered checksum must be rejected");
+        assert!(matches!(err, Error::UnknownCodeChecksum { .. }));
+    }
+
+    #[test]
+    fn verify_client_message_surfaces_vm_errors() {
+        let client_state = test_client_state();
+        let vm = MockVm {
+            has_code: true,
+            call_result: Err("invalid header".into()),
+        };
+
+        let err = client_state
+            .verify_client_message(&vm, &[])
+            .expect_err("a VM-reported failure must propagate");
+        assert!(matches!(err, Error::VmError { .. }));
+    }
+
+    #[test]
+    fn update_state_adopts_the_vms_returned_data_and_keeps_the_checksum() {
+        let client_state = test_client_state();
+        let vm = MockVm {
+            has_code: true,
+            call_result: Ok(vec![9, 9, 9]),
+        };
+
+        let (new_state, checksum_attribute) = client_state
+            .update_state(&vm, &[])
+            .expect("the mock VM call succeeds");
+
+        assert_eq!(new_state.data, vec![9, 9, 9]);
+        assert_eq!(new_state.checksum, client_state.checksum);
+        assert_eq!(new_state.latest_height, client_state.latest_height);
+        assert_eq!(checksum_attribute, new_state.checksum_attribute());
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        if raw.data.is_empty() {
+            return Err(Error::InvalidRawClientState {
+                reason: "data must not be empty".into(),
+            });
+        }
+
+        let checksum_len = raw.checksum.len();
+        let checksum = raw
+            .checksum
+            .try_into()
+            .map_err(|_| Error::InvalidChecksumLength { len: checksum_len })?;
+
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing latest_height".into(),
+            })?
+            .try_into()
+            .map_err(|_| Error::InvalidRawClientState {
+                reason: "invalid latest_height".into(),
+            })?;
+
+        Ok(Self {
+            data: raw.data,
+            checksum,
+            latest_height,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            data: value.data,
+            checksum: value.checksum.to_vec(),
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state<B: bytes::Buf>(buf: B) -> Result<ClientState, Error> {
+            <RawClientState as prost::Message>::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.as_slice()).map_err(Into::into)
+            }
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(value: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&value)
+                .expect("encoding to `Any` from `WasmClientState`"),
+        }
+    }
+}
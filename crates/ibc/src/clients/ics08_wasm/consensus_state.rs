@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ConsensusState as RawConsensusState;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics08_wasm::error::Error;
+use crate::core::ics02_client::error::ClientError;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+/// The consensus state of an 08-wasm client: an opaque blob holding the
+/// state the inner Wasm light client needs, as (de)serialized by that
+/// light client's own contract.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub data: Vec<u8>,
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        if raw.data.is_empty() {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "data must not be empty".into(),
+            });
+        }
+
+        Ok(Self { data: raw.data })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState { data: value.data }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state<B: bytes::Buf>(buf: B) -> Result<ConsensusState, Error> {
+            <RawConsensusState as prost::Message>::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.as_slice()).map_err(Into::into)
+            }
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(value: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&value)
+                .expect("encoding to `Any` from `WasmConsensusState`"),
+        }
+    }
+}
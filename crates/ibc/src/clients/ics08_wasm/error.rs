@@ -0,0 +1,39 @@
+use crate::prelude::*;
+
+use displaydoc::Display;
+
+use crate::core::ics02_client::error::ClientError;
+
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// decode error: `{0}`
+    Decode(prost::DecodeError),
+    /// the wasm code checksum must be a 32-byte SHA-256 digest, got `{len}` bytes
+    InvalidChecksumLength { len: usize },
+    /// the host is not aware of any wasm code with checksum `{checksum}`
+    UnknownCodeChecksum { checksum: String },
+    /// the wasm VM call returned an error: `{reason}`
+    VmError { reason: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        ClientError::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
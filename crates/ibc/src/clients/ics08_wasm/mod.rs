@@ -0,0 +1,9 @@
+//! Defines a light client wrapper that delegates verification to
+//! light-client logic compiled to Wasm bytecode and executed by a host Wasm
+//! VM (the CosmWasm-style light client pattern), so new client types can be
+//! added to a chain without a hard fork.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod vm;
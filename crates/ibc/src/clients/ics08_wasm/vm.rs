@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+/// The host-side interface to the Wasm VM that executes light-client
+/// bytecode registered under a checksum. A `ClientState` never interprets
+/// `data` itself: it hands it, together with the serialized header or
+/// misbehaviour, to the VM and treats the bytecode's output as the new
+/// state.
+pub trait WasmVm {
+    /// Returns `true` if bytecode for `checksum` has been registered with
+    /// the host.
+    fn has_code(&self, checksum: &[u8; 32]) -> bool;
+
+    /// Executes the bytecode registered under `checksum`, passing it the
+    /// client's current `data` and the serialized `client_message` (a
+    /// header or a misbehaviour), and returns the bytecode's own judgement
+    /// of the resulting state bytes.
+    ///
+    /// Errors are opaque VM/contract error strings: the caller wraps them
+    /// in [`crate::clients::ics08_wasm::error::Error::VmError`].
+    fn call(
+        &self,
+        checksum: &[u8; 32],
+        data: &[u8],
+        client_message: &[u8],
+    ) -> Result<Vec<u8>, String>;
+}
@@ -0,0 +1,152 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::beefy::v1::ClientState as RawClientState;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics11_beefy::consensus_state::ConsensusState;
+use crate::clients::ics11_beefy::error::Error;
+use crate::clients::ics11_beefy::header::Header;
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const BEEFY_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.ClientState";
+
+/// The client state of a BEEFY light client tracking a Substrate/Polkadot
+/// relay chain (or one of its parachains).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub latest_beefy_height: Height,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, latest_beefy_height: Height) -> Self {
+        Self {
+            chain_id,
+            latest_beefy_height,
+            frozen_height: None,
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    /// Verifies a new `header` against `consensus_state`, per the BEEFY
+    /// verification rules:
+    ///
+    /// 1. the commitment's signatures reach the `>2/3` threshold of the
+    ///    current authority set;
+    /// 2. each signer is a member of the authority set, per the attached
+    ///    Merkle proofs;
+    /// 3. the MMR leaf verifies against the payload's MMR root;
+    /// 4. the header's block number is strictly greater than the one
+    ///    already stored, rejecting replays of an older (or the same)
+    ///    commitment.
+    pub fn verify_header(
+        &self,
+        consensus_state: &ConsensusState,
+        header: &Header,
+    ) -> Result<(), Error> {
+        let commitment = &header.signed_commitment.commitment;
+
+        if commitment.block_number <= self.latest_beefy_height.revision_height() as u32 {
+            return Err(Error::StaleCommitment {
+                block_number: commitment.block_number,
+                latest: self.latest_beefy_height.revision_height() as u32,
+            });
+        }
+
+        header.signed_commitment.verify_signatures(
+            &consensus_state.current_authorities,
+            &header.validator_merkle_proofs,
+        )?;
+
+        header
+            .mmr_leaf_proof
+            .verify(&header.mmr_leaf, &commitment.payload.mmr_root)?;
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let chain_id = ChainId::from_string(&raw.chain_id);
+
+        let latest_beefy_height = raw
+            .latest_beefy_height
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing latest_beefy_height".into(),
+            })?
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "invalid latest_beefy_height".into(),
+            })?;
+
+        let frozen_height = raw
+            .frozen_height
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "invalid frozen_height".into(),
+            })?;
+
+        Ok(Self {
+            chain_id,
+            latest_beefy_height,
+            frozen_height,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            chain_id: value.chain_id.to_string(),
+            latest_beefy_height: Some(value.latest_beefy_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state<B: bytes::Buf>(buf: B) -> Result<ClientState, Error> {
+            <RawClientState as prost::Message>::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            BEEFY_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.as_slice()).map_err(Into::into)
+            }
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(value: ClientState) -> Self {
+        Any {
+            type_url: BEEFY_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&value)
+                .expect("encoding to `Any` from `BeefyClientState`"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::beefy::v1::ConsensusState as RawConsensusState;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics11_beefy::error::Error;
+use crate::clients::ics11_beefy::types::{AuthoritySetCommitment, H256};
+use crate::core::ics02_client::error::ClientError;
+use crate::core::timestamp::Timestamp;
+
+pub const BEEFY_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.ConsensusState";
+
+/// The consensus state of a BEEFY light client: the latest MMR root it has
+/// verified a commitment for, plus the current and next authority set
+/// commitments that future headers are checked against.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    pub mmr_root: H256,
+    pub current_authorities: AuthoritySetCommitment,
+    pub next_authorities: AuthoritySetCommitment,
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let mmr_root = raw
+            .mmr_root
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "mmr_root must be 32 bytes".into(),
+            })?;
+
+        let timestamp =
+            Timestamp::from_nanoseconds(raw.timestamp).map_err(|_| Error::InvalidRawHeader {
+                reason: "invalid timestamp".into(),
+            })?;
+
+        let current_authorities = raw
+            .current_authorities
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing current_authorities".into(),
+            })?
+            .try_into()?;
+        let next_authorities = raw
+            .next_authorities
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing next_authorities".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self {
+            timestamp,
+            mmr_root,
+            current_authorities,
+            next_authorities,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            timestamp: value.timestamp.nanoseconds(),
+            mmr_root: value.mmr_root.to_vec(),
+            current_authorities: Some(value.current_authorities.into()),
+            next_authorities: Some(value.next_authorities.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state<B: bytes::Buf>(buf: B) -> Result<ConsensusState, Error> {
+            <RawConsensusState as prost::Message>::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            BEEFY_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.as_slice()).map_err(Into::into)
+            }
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(value: ConsensusState) -> Self {
+        Any {
+            type_url: BEEFY_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&value)
+                .expect("encoding to `Any` from `BeefyConsensusState`"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+use displaydoc::Display;
+
+use crate::core::ics02_client::error::ClientError;
+
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client identifier `{client_id}`
+    InvalidRawClientId { client_id: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// decode error: `{0}`
+    Decode(prost::DecodeError),
+    /// commitment carries `{have}` signatures, `{required}` are required to reach the 2/3 threshold
+    InsufficientSignatures { have: u32, required: u32 },
+    /// authority at index `{authority_index}` signed the same commitment more than once
+    DuplicateAuthorityIndex { authority_index: u32 },
+    /// no Merkle proof was supplied for authority at index `{authority_index}`
+    MissingAuthorityProof { authority_index: u32 },
+    /// authority at index `{authority_index}` is not a member of the committed authority set
+    InvalidAuthorityMerkleProof { authority_index: u32 },
+    /// authority at index `{authority_index}` has an invalid public key
+    InvalidAuthorityPublicKey { authority_index: u32 },
+    /// signature from authority at index `{authority_index}` does not verify against the commitment
+    InvalidCommitmentSignature { authority_index: u32 },
+    /// MMR leaf at index `{leaf_index}` does not verify against the commitment's MMR root
+    InvalidMmrLeafProof { leaf_index: u64 },
+    /// commitment block number `{block_number}` is not greater than the latest trusted block number `{latest}`
+    StaleCommitment { block_number: u32, latest: u32 },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        ClientError::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
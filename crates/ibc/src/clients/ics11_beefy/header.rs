@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::beefy::v1::Header as RawHeader;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics11_beefy::error::Error;
+use crate::clients::ics11_beefy::types::{
+    MmrLeaf, MmrLeafProof, SignedCommitment, ValidatorMerkleProof,
+};
+use crate::core::ics02_client::error::ClientError;
+
+pub const BEEFY_HEADER_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.Header";
+
+/// A BEEFY header: a signed commitment to a new MMR root, the Merkle proofs
+/// that the signing authorities belong to the current authority set, and an
+/// MMR leaf (with its inclusion proof) linking a parachain/relay block to
+/// the root the commitment signs over.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub signed_commitment: SignedCommitment,
+    pub validator_merkle_proofs: Vec<ValidatorMerkleProof>,
+    pub mmr_leaf: MmrLeaf,
+    pub mmr_leaf_proof: MmrLeafProof,
+}
+
+impl Header {
+    pub fn block_number(&self) -> u32 {
+        self.signed_commitment.commitment.block_number
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        let signed_commitment = raw
+            .signed_commitment
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing signed_commitment".into(),
+            })?
+            .try_into()?;
+
+        let validator_merkle_proofs = raw
+            .validator_merkle_proofs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mmr_leaf = raw
+            .mmr_leaf
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing mmr_leaf".into(),
+            })?
+            .try_into()?;
+
+        let mmr_leaf_proof = raw
+            .mmr_leaf_proof
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing mmr_leaf_proof".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self {
+            signed_commitment,
+            validator_merkle_proofs,
+            mmr_leaf,
+            mmr_leaf_proof,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            signed_commitment: Some(value.signed_commitment.into()),
+            validator_merkle_proofs: value
+                .validator_merkle_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            mmr_leaf: Some(value.mmr_leaf.into()),
+            mmr_leaf_proof: Some(value.mmr_leaf_proof.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use core::ops::Deref;
+
+        fn decode_header<B: bytes::Buf>(buf: B) -> Result<Header, Error> {
+            <RawHeader as prost::Message>::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            BEEFY_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: BEEFY_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(&header)
+                .expect("encoding to `Any` from `BeefyHeader`"),
+        }
+    }
+}
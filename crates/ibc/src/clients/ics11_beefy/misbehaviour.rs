@@ -0,0 +1,217 @@
+use crate::prelude::*;
+
+use bytes::Buf;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::beefy::v1::Misbehaviour as RawMisbehaviour;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+
+use crate::clients::ics11_beefy::error::Error;
+use crate::clients::ics11_beefy::types::{
+    AuthoritySetCommitment, SignedCommitment, ValidatorMerkleProof,
+};
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::Height;
+
+pub const BEEFY_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.Misbehaviour";
+
+/// Evidence that the BEEFY authority set has equivocated: two signed
+/// commitments for the same block number, with different payloads, each
+/// independently meeting the `>2/3` signature threshold.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    client_id: ClientId,
+    commitment1: SignedCommitment,
+    authority_proofs1: Vec<ValidatorMerkleProof>,
+    commitment2: SignedCommitment,
+    authority_proofs2: Vec<ValidatorMerkleProof>,
+    authority_set: AuthoritySetCommitment,
+}
+
+impl Misbehaviour {
+    pub fn new(
+        client_id: ClientId,
+        commitment1: SignedCommitment,
+        authority_proofs1: Vec<ValidatorMerkleProof>,
+        commitment2: SignedCommitment,
+        authority_proofs2: Vec<ValidatorMerkleProof>,
+        authority_set: AuthoritySetCommitment,
+    ) -> Result<Self, Error> {
+        if commitment1.commitment.block_number != commitment2.commitment.block_number {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: format!(
+                    "commitments are for different block numbers ({} != {})",
+                    commitment1.commitment.block_number, commitment2.commitment.block_number
+                ),
+            });
+        }
+
+        if commitment1.commitment.payload == commitment2.commitment.payload {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "commitments commit to the same payload, this is not misbehaviour".into(),
+            });
+        }
+
+        commitment1.verify_signatures(&authority_set, &authority_proofs1)?;
+        commitment2.verify_signatures(&authority_set, &authority_proofs2)?;
+
+        Ok(Self {
+            client_id,
+            commitment1,
+            authority_proofs1,
+            commitment2,
+            authority_proofs2,
+            authority_set,
+        })
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn commitment1(&self) -> &SignedCommitment {
+        &self.commitment1
+    }
+
+    pub fn commitment2(&self) -> &SignedCommitment {
+        &self.commitment2
+    }
+
+    pub fn authority_set(&self) -> &AuthoritySetCommitment {
+        &self.authority_set
+    }
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.commitment1.commitment.block_number as u64)
+            .expect("block number fits in a height")
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let client_id = raw
+            .client_id
+            .parse()
+            .map_err(|_| Error::InvalidRawClientId {
+                client_id: raw.client_id.clone(),
+            })?;
+
+        let commitment1 = raw
+            .commitment_1
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing commitment1".into(),
+            })?
+            .try_into()?;
+        let proofs1 = raw
+            .authority_proofs_1
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let commitment2 = raw
+            .commitment_2
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing commitment2".into(),
+            })?
+            .try_into()?;
+        let proofs2 = raw
+            .authority_proofs_2
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let authority_set = raw
+            .authority_set
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing authority_set".into(),
+            })?
+            .try_into()?;
+
+        Self::new(
+            client_id,
+            commitment1,
+            proofs1,
+            commitment2,
+            proofs2,
+            authority_set,
+        )
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            commitment_1: Some(value.commitment1.into()),
+            authority_proofs_1: value
+                .authority_proofs1
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            commitment_2: Some(value.commitment2.into()),
+            authority_proofs_2: value
+                .authority_proofs2
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            authority_set: Some(value.authority_set.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, ClientError> {
+        use core::ops::Deref;
+
+        fn decode_misbehaviour<B: Buf>(buf: B) -> Result<Misbehaviour, Error> {
+            RawMisbehaviour::decode(buf)
+                .map_err(Error::Decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            BEEFY_MISBEHAVIOUR_TYPE_URL => {
+                decode_misbehaviour(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: BEEFY_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(&misbehaviour)
+                .expect("encoding to `Any` from `BeefyMisbehaviour`"),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "{} block_number: {}",
+            self.client_id, self.commitment1.commitment.block_number,
+        )
+    }
+}
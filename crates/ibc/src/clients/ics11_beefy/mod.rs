@@ -0,0 +1,10 @@
+//! Defines the client state, consensus state, header and misbehaviour types
+//! for a light client tracking a Substrate/Polkadot-based chain via the
+//! BEEFY finality gadget.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+pub mod types;
@@ -0,0 +1,702 @@
+//! Supporting types for the BEEFY light client: the MMR-backed commitment
+//! that the authority set signs over, the sparse signature vector carried by
+//! a header, and the Merkle proofs needed to verify both the signing
+//! authorities and the MMR leaf against the commitments stored on chain.
+
+use crate::prelude::*;
+
+use alloc::collections::BTreeSet;
+use ibc_proto::ibc::lightclients::beefy::v1::{
+    AuthoritySetCommitment as RawAuthoritySetCommitment, Commitment as RawCommitment,
+    MmrLeaf as RawMmrLeaf, MmrLeafProof as RawMmrLeafProof, Payload as RawPayload,
+    SignedCommitment as RawSignedCommitment, ValidatorMerkleProof as RawValidatorMerkleProof,
+};
+use k256::ecdsa::signature::Verifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+
+use super::error::Error;
+
+/// A 32-byte Merkle/MMR digest.
+pub type H256 = [u8; 32];
+
+/// A secp256k1 public key, compressed encoding.
+pub type AuthorityId = [u8; 33];
+
+/// Commits to the current (or next) BEEFY authority set: a Merkle root over
+/// the authorities' public keys, plus the number of authorities, which is
+/// needed to compute the `2/3` signature threshold.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthoritySetCommitment {
+    pub root: H256,
+    pub len: u32,
+}
+
+/// The payload carried by a BEEFY commitment. In addition to the MMR root,
+/// a real payload is a list of `(2-byte id, bytes)` pairs; we only need the
+/// MMR root for verification purposes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    pub mmr_root: H256,
+}
+
+/// A BEEFY commitment: the payload the authority set agreed on finality for,
+/// together with the block number it was produced at and the id of the
+/// authority set that signed it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commitment {
+    pub payload: Payload,
+    pub block_number: u32,
+    pub validator_set_id: u64,
+}
+
+/// A commitment together with a sparse vector of `(authority_index,
+/// signature)` pairs: BEEFY gossips commitments as soon as a threshold of
+/// signatures is known, so most headers will only carry a subset of the
+/// full authority set's signatures.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedCommitment {
+    pub commitment: Commitment,
+    pub signatures: Vec<(u32, Vec<u8>)>,
+}
+
+/// A Merkle proof that `authority` at `authority_index` is a leaf of the
+/// authority-set Merkle tree committed to by [`AuthoritySetCommitment::root`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorMerkleProof {
+    pub authority: AuthorityId,
+    pub authority_index: u32,
+    pub proof: Vec<H256>,
+}
+
+/// A leaf of the Merkle Mountain Range committed to by a BEEFY payload,
+/// linking a relay/parachain block to the finalized MMR root.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmrLeaf {
+    pub block_number: u32,
+    pub parent_hash: H256,
+    pub parachain_heads: H256,
+    pub next_authority_set: AuthoritySetCommitment,
+}
+
+/// An MMR inclusion proof for a single [`MmrLeaf`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmrLeafProof {
+    pub leaf_index: u64,
+    pub leaf_count: u64,
+    pub items: Vec<H256>,
+}
+
+impl TryFrom<RawAuthoritySetCommitment> for AuthoritySetCommitment {
+    type Error = Error;
+
+    fn try_from(raw: RawAuthoritySetCommitment) -> Result<Self, Self::Error> {
+        let root = raw.root.try_into().map_err(|_| Error::InvalidRawHeader {
+            reason: "authority set root must be 32 bytes".into(),
+        })?;
+
+        Ok(Self { root, len: raw.len })
+    }
+}
+
+impl From<AuthoritySetCommitment> for RawAuthoritySetCommitment {
+    fn from(value: AuthoritySetCommitment) -> Self {
+        RawAuthoritySetCommitment {
+            root: value.root.to_vec(),
+            len: value.len,
+        }
+    }
+}
+
+impl TryFrom<RawPayload> for Payload {
+    type Error = Error;
+
+    fn try_from(raw: RawPayload) -> Result<Self, Self::Error> {
+        let mmr_root = raw
+            .mmr_root
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "payload mmr_root must be 32 bytes".into(),
+            })?;
+        Ok(Self { mmr_root })
+    }
+}
+
+impl From<Payload> for RawPayload {
+    fn from(value: Payload) -> Self {
+        RawPayload {
+            mmr_root: value.mmr_root.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<RawCommitment> for Commitment {
+    type Error = Error;
+
+    fn try_from(raw: RawCommitment) -> Result<Self, Self::Error> {
+        let payload = raw
+            .payload
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing payload".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self {
+            payload,
+            block_number: raw.block_number,
+            validator_set_id: raw.validator_set_id,
+        })
+    }
+}
+
+impl From<Commitment> for RawCommitment {
+    fn from(value: Commitment) -> Self {
+        RawCommitment {
+            payload: Some(value.payload.into()),
+            block_number: value.block_number,
+            validator_set_id: value.validator_set_id,
+        }
+    }
+}
+
+impl TryFrom<RawSignedCommitment> for SignedCommitment {
+    type Error = Error;
+
+    fn try_from(raw: RawSignedCommitment) -> Result<Self, Self::Error> {
+        let commitment = raw
+            .commitment
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing commitment".into(),
+            })?
+            .try_into()?;
+
+        let signatures = raw
+            .signatures
+            .into_iter()
+            .map(|sig| (sig.authority_index, sig.signature))
+            .collect();
+
+        Ok(Self {
+            commitment,
+            signatures,
+        })
+    }
+}
+
+impl From<SignedCommitment> for RawSignedCommitment {
+    fn from(value: SignedCommitment) -> Self {
+        use ibc_proto::ibc::lightclients::beefy::v1::Signature as RawSignature;
+
+        RawSignedCommitment {
+            commitment: Some(value.commitment.into()),
+            signatures: value
+                .signatures
+                .into_iter()
+                .map(|(authority_index, signature)| RawSignature {
+                    authority_index,
+                    signature,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<RawValidatorMerkleProof> for ValidatorMerkleProof {
+    type Error = Error;
+
+    fn try_from(raw: RawValidatorMerkleProof) -> Result<Self, Self::Error> {
+        let authority = raw
+            .authority
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "authority public key must be 33 bytes".into(),
+            })?;
+
+        let proof = raw
+            .proof
+            .into_iter()
+            .map(|item| {
+                item.try_into().map_err(|_| Error::InvalidRawHeader {
+                    reason: "merkle proof item must be 32 bytes".into(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            authority,
+            authority_index: raw.authority_index,
+            proof,
+        })
+    }
+}
+
+impl From<ValidatorMerkleProof> for RawValidatorMerkleProof {
+    fn from(value: ValidatorMerkleProof) -> Self {
+        RawValidatorMerkleProof {
+            authority: value.authority.to_vec(),
+            authority_index: value.authority_index,
+            proof: value.proof.into_iter().map(|item| item.to_vec()).collect(),
+        }
+    }
+}
+
+impl TryFrom<RawMmrLeaf> for MmrLeaf {
+    type Error = Error;
+
+    fn try_from(raw: RawMmrLeaf) -> Result<Self, Self::Error> {
+        let parent_hash = raw
+            .parent_hash
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "parent_hash must be 32 bytes".into(),
+            })?;
+        let parachain_heads =
+            raw.parachain_heads
+                .try_into()
+                .map_err(|_| Error::InvalidRawHeader {
+                    reason: "parachain_heads must be 32 bytes".into(),
+                })?;
+        let next_authority_set = raw
+            .next_authority_set
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing next_authority_set".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self {
+            block_number: raw.block_number,
+            parent_hash,
+            parachain_heads,
+            next_authority_set,
+        })
+    }
+}
+
+impl From<MmrLeaf> for RawMmrLeaf {
+    fn from(value: MmrLeaf) -> Self {
+        RawMmrLeaf {
+            block_number: value.block_number,
+            parent_hash: value.parent_hash.to_vec(),
+            parachain_heads: value.parachain_heads.to_vec(),
+            next_authority_set: Some(value.next_authority_set.into()),
+        }
+    }
+}
+
+impl TryFrom<RawMmrLeafProof> for MmrLeafProof {
+    type Error = Error;
+
+    fn try_from(raw: RawMmrLeafProof) -> Result<Self, Self::Error> {
+        let items = raw
+            .items
+            .into_iter()
+            .map(|item| {
+                item.try_into().map_err(|_| Error::InvalidRawHeader {
+                    reason: "MMR leaf proof item must be 32 bytes".into(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            leaf_index: raw.leaf_index,
+            leaf_count: raw.leaf_count,
+            items,
+        })
+    }
+}
+
+impl From<MmrLeafProof> for RawMmrLeafProof {
+    fn from(value: MmrLeafProof) -> Self {
+        RawMmrLeafProof {
+            leaf_index: value.leaf_index,
+            leaf_count: value.leaf_count,
+            items: value.items.into_iter().map(|item| item.to_vec()).collect(),
+        }
+    }
+}
+
+impl AuthoritySetCommitment {
+    /// Number of signatures required to reach the `>2/3` threshold of this
+    /// authority set.
+    pub fn threshold(&self) -> u32 {
+        let len = self.len as u64;
+        ((len * 2) / 3 + 1) as u32
+    }
+}
+
+impl SignedCommitment {
+    /// Verifies that the signatures attached to this commitment reach the
+    /// `>2/3` threshold of `authority_set`, that each signer is a member of
+    /// `authority_set` (per the accompanying `proofs`) and that each
+    /// signature is valid over the commitment.
+    pub fn verify_signatures(
+        &self,
+        authority_set: &AuthoritySetCommitment,
+        proofs: &[ValidatorMerkleProof],
+    ) -> Result<(), Error> {
+        let threshold = authority_set.threshold();
+        if (self.signatures.len() as u32) < threshold {
+            return Err(Error::InsufficientSignatures {
+                have: self.signatures.len() as u32,
+                required: threshold,
+            });
+        }
+
+        // The threshold is only meaningful if every `authority_index` is
+        // distinct: otherwise a single valid signature repeated `threshold`
+        // times would satisfy the length check above without a real
+        // supermajority of the authority set ever signing.
+        let mut seen_indices = BTreeSet::new();
+        for (authority_index, _) in &self.signatures {
+            if !seen_indices.insert(*authority_index) {
+                return Err(Error::DuplicateAuthorityIndex {
+                    authority_index: *authority_index,
+                });
+            }
+        }
+
+        let commitment_bytes = self.commitment.signing_bytes();
+
+        for (authority_index, signature) in &self.signatures {
+            let proof = proofs
+                .iter()
+                .find(|p| p.authority_index == *authority_index)
+                .ok_or(Error::MissingAuthorityProof {
+                    authority_index: *authority_index,
+                })?;
+
+            proof.verify(authority_set)?;
+
+            let verifying_key = VerifyingKey::from_sec1_bytes(&proof.authority).map_err(|_| {
+                Error::InvalidAuthorityPublicKey {
+                    authority_index: *authority_index,
+                }
+            })?;
+            let signature = Signature::try_from(signature.as_slice()).map_err(|_| {
+                Error::InvalidCommitmentSignature {
+                    authority_index: *authority_index,
+                }
+            })?;
+
+            verifying_key
+                .verify(&commitment_bytes, &signature)
+                .map_err(|_| Error::InvalidCommitmentSignature {
+                    authority_index: *authority_index,
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Commitment {
+    /// The canonical byte encoding that authorities sign over.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 + 4 + 8);
+        bytes.extend_from_slice(&self.payload.mmr_root);
+        bytes.extend_from_slice(&self.block_number.to_be_bytes());
+        bytes.extend_from_slice(&self.validator_set_id.to_be_bytes());
+        bytes
+    }
+}
+
+impl ValidatorMerkleProof {
+    /// Verifies that `self.authority` is a leaf of the Merkle tree committed
+    /// to by `authority_set.root`.
+    pub fn verify(&self, authority_set: &AuthoritySetCommitment) -> Result<(), Error> {
+        let mut hash = sha256(&self.authority);
+        for sibling in &self.proof {
+            hash = if &hash <= sibling {
+                sha256(&[hash.as_slice(), sibling.as_slice()].concat())
+            } else {
+                sha256(&[sibling.as_slice(), hash.as_slice()].concat())
+            };
+        }
+
+        if hash == authority_set.root {
+            Ok(())
+        } else {
+            Err(Error::InvalidAuthorityMerkleProof {
+                authority_index: self.authority_index,
+            })
+        }
+    }
+}
+
+impl MmrLeafProof {
+    /// Verifies that `leaf` is included, at its claimed `leaf_index` among
+    /// `leaf_count` leaves, in the MMR committed to by `mmr_root`.
+    ///
+    /// An MMR of `leaf_count` leaves is a forest of perfect binary "peaks",
+    /// one per set bit of `leaf_count`. Verification climbs `leaf` to the
+    /// root of the peak it belongs to -- using the bits of its position
+    /// within that peak to pick the hashing order at each level, so a leaf
+    /// can't be proven at a position other than its own -- and then bags
+    /// that peak together with the (supplied) roots of every other peak,
+    /// left to right, into the final MMR root.
+    pub fn verify(&self, leaf: &MmrLeaf, mmr_root: &H256) -> Result<(), Error> {
+        let invalid = || Error::InvalidMmrLeafProof {
+            leaf_index: self.leaf_index,
+        };
+
+        if self.leaf_count == 0 || self.leaf_index >= self.leaf_count {
+            return Err(invalid());
+        }
+
+        let peak_heights = mmr_peak_heights(self.leaf_count);
+
+        let mut offset = 0u64;
+        let mut peak_pos = None;
+        let mut local_index = 0u64;
+        for (i, height) in peak_heights.iter().enumerate() {
+            let peak_leaves = 1u64 << height;
+            if self.leaf_index < offset + peak_leaves {
+                peak_pos = Some(i);
+                local_index = self.leaf_index - offset;
+                break;
+            }
+            offset += peak_leaves;
+        }
+        let peak_pos = peak_pos.ok_or_else(invalid)?;
+        let height = peak_heights[peak_pos];
+
+        let mut items = self.items.iter();
+
+        // Climb from the leaf to its peak's root: the bits of `local_index`
+        // (LSB first) say whether the leaf (or its ancestor) is a left or
+        // right child at each level.
+        let mut peak_root = sha256(&leaf.encode());
+        for _ in 0..height {
+            let sibling = items.next().ok_or_else(invalid)?;
+            peak_root = if local_index % 2 == 0 {
+                sha256(&[peak_root.as_slice(), sibling.as_slice()].concat())
+            } else {
+                sha256(&[sibling.as_slice(), peak_root.as_slice()].concat())
+            };
+            local_index /= 2;
+        }
+
+        // Bag every peak's root, left to right, into the final MMR root.
+        let mut acc: Option<H256> = None;
+        for (i, _) in peak_heights.iter().enumerate() {
+            let root = if i == peak_pos {
+                peak_root
+            } else {
+                *items.next().ok_or_else(invalid)?
+            };
+            acc = Some(match acc {
+                None => root,
+                Some(prev) => sha256(&[prev.as_slice(), root.as_slice()].concat()),
+            });
+        }
+
+        if acc.as_ref() == Some(mmr_root) {
+            Ok(())
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+/// The heights of an MMR's peaks, from tallest to shortest: one per set bit
+/// of `leaf_count`, each a perfect binary tree of `2^height` leaves.
+fn mmr_peak_heights(leaf_count: u64) -> Vec<u32> {
+    let mut heights = Vec::new();
+    for height in (0..u64::BITS).rev() {
+        if leaf_count & (1u64 << height) != 0 {
+            heights.push(height);
+        }
+    }
+    heights
+}
+
+impl MmrLeaf {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + 32 + 32 + 32 + 4);
+        bytes.extend_from_slice(&self.block_number.to_be_bytes());
+        bytes.extend_from_slice(&self.parent_hash);
+        bytes.extend_from_slice(&self.parachain_heads);
+        bytes.extend_from_slice(&self.next_authority_set.root);
+        bytes.extend_from_slice(&self.next_authority_set.len.to_be_bytes());
+        bytes
+    }
+}
+
+fn sha256(bytes: &[u8]) -> H256 {
+    use sha2::Digest;
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::signature::Signer;
+    use k256::ecdsa::SigningKey;
+
+    fn test_leaf(block_number: u32) -> MmrLeaf {
+        MmrLeaf {
+            block_number,
+            parent_hash: [block_number as u8; 32],
+            parachain_heads: [0u8; 32],
+            next_authority_set: AuthoritySetCommitment {
+                root: [0u8; 32],
+                len: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn mmr_leaf_proof_verifies_against_its_leaf() {
+        let leaf0 = test_leaf(0);
+        let leaf1 = test_leaf(1);
+        let hash0 = sha256(&leaf0.encode());
+        let hash1 = sha256(&leaf1.encode());
+        let mmr_root = sha256(&[hash0.as_slice(), hash1.as_slice()].concat());
+
+        let proof = MmrLeafProof {
+            leaf_index: 0,
+            leaf_count: 2,
+            items: vec![hash1],
+        };
+
+        assert!(proof.verify(&leaf0, &mmr_root).is_ok());
+    }
+
+    #[test]
+    fn mmr_leaf_proof_rejects_wrong_leaf() {
+        let leaf0 = test_leaf(0);
+        let leaf1 = test_leaf(1);
+        let hash0 = sha256(&leaf0.encode());
+        let hash1 = sha256(&leaf1.encode());
+        let mmr_root = sha256(&[hash0.as_slice(), hash1.as_slice()].concat());
+
+        let proof = MmrLeafProof {
+            leaf_index: 0,
+            leaf_count: 2,
+            items: vec![hash1],
+        };
+
+        // `leaf1` was not the leaf committed at index 0.
+        assert!(proof.verify(&leaf1, &mmr_root).is_err());
+    }
+
+    #[test]
+    fn mmr_leaf_proof_rejects_out_of_range_leaf_index() {
+        let leaf0 = test_leaf(0);
+        let proof = MmrLeafProof {
+            leaf_index: 2,
+            leaf_count: 2,
+            items: vec![],
+        };
+
+        assert!(proof.verify(&leaf0, &[0u8; 32]).is_err());
+    }
+
+    fn signer_with_authority() -> (SigningKey, AuthorityId) {
+        let signing_key = SigningKey::from_slice(&[1u8; 32]).expect("valid scalar");
+        let authority: AuthorityId = signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("compressed sec1 point is 33 bytes");
+        (signing_key, authority)
+    }
+
+    fn single_authority_commitment(
+        block_number: u32,
+    ) -> (SigningKey, AuthorityId, AuthoritySetCommitment, Commitment) {
+        let (signing_key, authority) = signer_with_authority();
+        let authority_set = AuthoritySetCommitment {
+            root: sha256(&authority),
+            len: 1,
+        };
+        let commitment = Commitment {
+            payload: Payload {
+                mmr_root: [0u8; 32],
+            },
+            block_number,
+            validator_set_id: 0,
+        };
+        (signing_key, authority, authority_set, commitment)
+    }
+
+    #[test]
+    fn verify_signatures_accepts_a_valid_threshold_signature() {
+        let (signing_key, authority, authority_set, commitment) = single_authority_commitment(1);
+
+        let signature: k256::ecdsa::Signature = signing_key.sign(&commitment.signing_bytes());
+        let signed_commitment = SignedCommitment {
+            commitment,
+            signatures: vec![(0, signature.to_bytes().to_vec())],
+        };
+        let proofs = vec![ValidatorMerkleProof {
+            authority,
+            authority_index: 0,
+            proof: vec![],
+        }];
+
+        assert!(signed_commitment
+            .verify_signatures(&authority_set, &proofs)
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_signatures_rejects_duplicate_authority_indices() {
+        let (signing_key, authority, authority_set, commitment) = single_authority_commitment(1);
+
+        let signature: k256::ecdsa::Signature = signing_key.sign(&commitment.signing_bytes());
+        let signed_commitment = SignedCommitment {
+            commitment,
+            signatures: vec![
+                (0, signature.to_bytes().to_vec()),
+                (0, signature.to_bytes().to_vec()),
+            ],
+        };
+        let proofs = vec![ValidatorMerkleProof {
+            authority,
+            authority_index: 0,
+            proof: vec![],
+        }];
+
+        let err = signed_commitment
+            .verify_signatures(&authority_set, &proofs)
+            .expect_err("duplicate authority index must not count twice toward the threshold");
+        assert!(matches!(err, Error::DuplicateAuthorityIndex { .. }));
+    }
+
+    #[test]
+    fn verify_signatures_rejects_below_threshold() {
+        let (signing_key, authority, _, commitment) = single_authority_commitment(1);
+        let authority_set = AuthoritySetCommitment {
+            root: sha256(&authority),
+            // With 4 authorities the threshold is 3, so a single signature
+            // is insufficient even though it is otherwise valid.
+            len: 4,
+        };
+
+        let signature: k256::ecdsa::Signature = signing_key.sign(&commitment.signing_bytes());
+        let signed_commitment = SignedCommitment {
+            commitment,
+            signatures: vec![(0, signature.to_bytes().to_vec())],
+        };
+        let proofs = vec![ValidatorMerkleProof {
+            authority,
+            authority_index: 0,
+            proof: vec![],
+        }];
+
+        let err = signed_commitment
+            .verify_signatures(&authority_set, &proofs)
+            .expect_err("one signature must not satisfy a threshold of three");
+        assert!(matches!(err, Error::InsufficientSignatures { .. }));
+    }
+}
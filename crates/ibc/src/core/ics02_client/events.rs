@@ -0,0 +1,171 @@
+///! This module holds the abci event attributes for IBC events emitted when
+///! misbehaviour is submitted against a client and when a client is frozen
+///! as a result.
+use crate::prelude::*;
+
+use derive_more::From;
+use tendermint_proto::abci;
+
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::events::ModuleEventAttribute;
+use crate::Height;
+
+const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
+const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
+const CONSENSUS_HEIGHT_ATTRIBUTE_KEY: &str = "consensus_height";
+const FROZEN_HEIGHT_ATTRIBUTE_KEY: &str = "frozen_height";
+
+pub const SUBMIT_MISBEHAVIOUR_EVENT: &str = "submit_misbehaviour";
+pub const CLIENT_FROZEN_EVENT: &str = "client_frozen";
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ClientIdAttribute {
+    pub client_id: ClientId,
+}
+
+impl From<ClientIdAttribute> for abci::EventAttribute {
+    fn from(attr: ClientIdAttribute) -> Self {
+        ModuleEventAttribute::from((CLIENT_ID_ATTRIBUTE_KEY, attr.client_id.as_str())).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ClientTypeAttribute {
+    pub client_type: ClientType,
+}
+
+impl From<ClientTypeAttribute> for abci::EventAttribute {
+    fn from(attr: ClientTypeAttribute) -> Self {
+        ModuleEventAttribute::from((CLIENT_TYPE_ATTRIBUTE_KEY, attr.client_type.as_str())).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ConsensusHeightAttribute {
+    pub consensus_height: Height,
+}
+
+impl From<ConsensusHeightAttribute> for abci::EventAttribute {
+    fn from(attr: ConsensusHeightAttribute) -> Self {
+        ModuleEventAttribute::from((
+            CONSENSUS_HEIGHT_ATTRIBUTE_KEY,
+            attr.consensus_height.to_string().as_str(),
+        ))
+        .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct FrozenHeightAttribute {
+    pub frozen_height: Height,
+}
+
+impl From<FrozenHeightAttribute> for abci::EventAttribute {
+    fn from(attr: FrozenHeightAttribute) -> Self {
+        ModuleEventAttribute::from((
+            FROZEN_HEIGHT_ATTRIBUTE_KEY,
+            attr.frozen_height.to_string().as_str(),
+        ))
+        .into()
+    }
+}
+
+/// Emitted when misbehaviour evidence is submitted against a client, before
+/// the client is actually frozen, so that relayers and indexers can detect
+/// the submission from the event log.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmitMisbehaviour {
+    pub client_id: ClientIdAttribute,
+    pub client_type: ClientTypeAttribute,
+    pub consensus_height: ConsensusHeightAttribute,
+}
+
+impl From<SubmitMisbehaviour> for abci::Event {
+    fn from(ev: SubmitMisbehaviour) -> Self {
+        abci::Event {
+            kind: SUBMIT_MISBEHAVIOUR_EVENT.to_owned(),
+            attributes: vec![
+                ev.client_id.into(),
+                ev.client_type.into(),
+                ev.consensus_height.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when a client is frozen as a result of misbehaviour, carrying the
+/// height at which it was frozen.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientFrozen {
+    pub client_id: ClientIdAttribute,
+    pub client_type: ClientTypeAttribute,
+    pub frozen_height: FrozenHeightAttribute,
+}
+
+impl From<ClientFrozen> for abci::Event {
+    fn from(ev: ClientFrozen) -> Self {
+        abci::Event {
+            kind: CLIENT_FROZEN_EVENT.to_owned(),
+            attributes: vec![
+                ev.client_id.into(),
+                ev.client_type.into(),
+                ev.frozen_height.into(),
+            ],
+        }
+    }
+}
@@ -1,5 +1,7 @@
 ///! This module holds all the abci event attributes for IBC events emitted
 ///! during the channel handshake.
+use crate::prelude::*;
+
 use derive_more::From;
 use tendermint_proto::abci;
 
@@ -17,6 +19,7 @@ const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub(super) const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 const VERSION_ATTRIBUTE_KEY: &str = "version";
+const CHECKSUM_ATTRIBUTE_KEY: &str = "checksum";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -174,3 +177,32 @@ impl From<VersionAttribute> for abci::EventAttribute {
         ModuleEventAttribute::from((VERSION_ATTRIBUTE_KEY, attr.version.as_str())).into()
     }
 }
+
+/// The hex-encoded SHA-256 checksum of the Wasm bytecode backing an 08-wasm
+/// light client, so that relayers and indexers can tell which bytecode
+/// produced a given client from the event log alone.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChecksumAttribute {
+    pub checksum: Vec<u8>,
+}
+
+impl From<ChecksumAttribute> for abci::EventAttribute {
+    fn from(attr: ChecksumAttribute) -> Self {
+        let checksum_hex = String::from_utf8(subtle_encoding::hex::encode(attr.checksum))
+            .expect("hex-encoded bytes are valid UTF-8");
+        ModuleEventAttribute::from((CHECKSUM_ATTRIBUTE_KEY, checksum_hex.as_str())).into()
+    }
+}